@@ -1,8 +1,8 @@
 use crate::{
-    CoordinateType, GeometryCollection, LineString,
+    Coordinate, CoordinateType, GeometryCollection, LineString,
     MultiPolygon, Point, Polygon, Rect, Triangle, GeometryCow
 };
-use num_traits::Float;
+use num_traits::{Float, NumCast, ToPrimitive};
 
 use crate::algorithm::winding_order::twice_signed_ring_area;
 
@@ -103,6 +103,166 @@ where
 
 ///////////////////////////////////////////////
 
+/// Radius of the Earth in meters used by the Chamberlain & Duquette formula
+/// (the WGS84 equatorial radius).
+const EARTH_RADIUS_M: f64 = 6_378_137.0;
+
+/// Geodesic area of a geometry whose coordinates are longitude/latitude
+/// degrees on a sphere.
+///
+/// Uses the Chamberlain & Duquette formula on a sphere, which approximates
+/// the area on the WGS84 ellipsoid; edges along parallels and meridians are
+/// measured exactly, great-circle edges only approximately. Returns the
+/// result in square meters.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::polygon;
+/// use geo::algorithm::area::GeodesicArea;
+///
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 1., y: 0.),
+///     (x: 1., y: 1.),
+///     (x: 0., y: 1.),
+///     (x: 0., y: 0.),
+/// ];
+///
+/// assert_relative_eq!(polygon.geodesic_area().abs(), 12_391_399_902., epsilon = 1.);
+/// ```
+pub trait GeodesicArea<'a, T>
+where
+    T: Float,
+{
+    fn geodesic_area(&'a self) -> T;
+}
+
+// Unwrap a longitude difference into (-π, π] so a ring crossing the
+// antimeridian doesn't pick up a spurious ±2π jump.
+fn unwrap_antimeridian(delta: f64) -> f64 {
+    let two_pi = 2. * std::f64::consts::PI;
+    let wrapped = delta % two_pi;
+    if wrapped <= -std::f64::consts::PI {
+        wrapped + two_pi
+    } else if wrapped > std::f64::consts::PI {
+        wrapped - two_pi
+    } else {
+        wrapped
+    }
+}
+
+// Chamberlain & Duquette area of a single closed ring (vertex n == vertex 0),
+// in square meters. Lon/lat degrees are converted to radians and the sum is
+// accumulated in f64 regardless of T.
+fn chamberlain_duquette_ring_area<T: Float>(linestring: &LineString<T>) -> f64 {
+    let coords = &linestring.0;
+    // A closed ring needs at least 3 distinct points plus the repeated
+    // closing vertex; anything smaller is degenerate.
+    let n = coords.len().saturating_sub(1);
+    if n < 3 {
+        return 0.;
+    }
+
+    let lambda: Vec<f64> = coords[..n]
+        .iter()
+        .map(|c| c.x.to_f64().unwrap().to_radians())
+        .collect();
+    let phi: Vec<f64> = coords[..n]
+        .iter()
+        .map(|c| c.y.to_f64().unwrap().to_radians())
+        .collect();
+
+    let sum: f64 = (0..n)
+        .map(|i| {
+            let prev = (i + n - 1) % n;
+            let next = (i + 1) % n;
+            unwrap_antimeridian(lambda[next] - lambda[prev]) * phi[i].sin()
+        })
+        .sum();
+
+    (EARTH_RADIUS_M * EARTH_RADIUS_M / 2.) * sum
+}
+
+fn geodesic_area_polygon<T: Float>(polygon: &Polygon<T>) -> f64 {
+    polygon.interiors().iter().fold(
+        chamberlain_duquette_ring_area(polygon.exterior()),
+        |total, next| total - chamberlain_duquette_ring_area(next),
+    )
+}
+
+fn geodesic_area_multi_polygon<T: Float>(multi_polygon: &MultiPolygon<T>) -> f64 {
+    multi_polygon
+        .0
+        .iter()
+        .fold(0., |total, next| total + geodesic_area_polygon(next))
+}
+
+fn geodesic_area_geometry_collection<T: Float>(
+    geometry_collection: &GeometryCollection<T>,
+) -> f64 {
+    geometry_collection.iter().fold(0., |total, geometry| {
+        total + geometry.geodesic_area().to_f64().unwrap()
+    })
+}
+
+fn geodesic_area_rect<T: Float>(rect: &Rect<T>) -> f64 {
+    let ring = LineString(vec![
+        Coordinate {
+            x: rect.min().x,
+            y: rect.min().y,
+        },
+        Coordinate {
+            x: rect.max().x,
+            y: rect.min().y,
+        },
+        Coordinate {
+            x: rect.max().x,
+            y: rect.max().y,
+        },
+        Coordinate {
+            x: rect.min().x,
+            y: rect.max().y,
+        },
+        Coordinate {
+            x: rect.min().x,
+            y: rect.min().y,
+        },
+    ]);
+    chamberlain_duquette_ring_area(&ring)
+}
+
+fn geodesic_area_triangle<T: Float>(triangle: &Triangle<T>) -> f64 {
+    let ring = LineString(vec![triangle.0, triangle.1, triangle.2, triangle.0]);
+    chamberlain_duquette_ring_area(&ring)
+}
+
+impl<'a, I: 'a, T: 'a> GeodesicArea<'a, T> for I
+where
+    &'a I: Into<GeometryCow<'a, T>>,
+    T: Float,
+{
+    fn geodesic_area(&'a self) -> T {
+        let geometry_cow: GeometryCow<'a, T> = self.into();
+        let area = match geometry_cow {
+            GeometryCow::Point(_) => 0.,
+            GeometryCow::Line(_) => 0.,
+            GeometryCow::LineString(_) => 0.,
+            GeometryCow::Polygon(g) => geodesic_area_polygon(&*g),
+            GeometryCow::MultiPoint(_) => 0.,
+            GeometryCow::MultiLineString(_) => 0.,
+            GeometryCow::MultiPolygon(g) => geodesic_area_multi_polygon(&*g),
+            GeometryCow::GeometryCollection(g) => geodesic_area_geometry_collection(&*g),
+            GeometryCow::Rect(g) => geodesic_area_rect(&*g),
+            GeometryCow::Triangle(g) => geodesic_area_triangle(&*g),
+        };
+        NumCast::from(area).unwrap_or_else(T::infinity)
+    }
+}
+
+///////////////////////////////////////////////
+
 struct NewPoint<T: Float>(Point<T>);
 
 impl<'a, T: Float> Into<GeometryCow<'a, T>> for &'a NewPoint<T> {
@@ -135,8 +295,11 @@ fn foo() {
 
 #[cfg(test)]
 mod test {
-    use crate::algorithm::area::Area;
-    use crate::{line_string, polygon, Coordinate, Line, MultiPolygon, Polygon, Rect, Triangle};
+    use crate::algorithm::area::{Area, GeodesicArea};
+    use crate::{
+        line_string, polygon, Coordinate, Geometry, GeometryCollection, Line, MultiPolygon,
+        Polygon, Rect, Triangle,
+    };
 
     // Area of the polygon
     #[test]
@@ -248,4 +411,115 @@ mod test {
         );
         assert_relative_eq!(triangle.area(), -0.5);
     }
+
+    #[test]
+    fn geodesic_area_degenerate_polygon_test() {
+        let poly: Polygon<f64> = polygon![];
+        assert_relative_eq!(poly.geodesic_area(), 0.);
+
+        let poly = polygon![(x: 1., y: 0.)];
+        assert_relative_eq!(poly.geodesic_area(), 0.);
+    }
+
+    #[test]
+    fn geodesic_area_one_degree_square_test() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        assert_relative_eq!(polygon.geodesic_area(), -12_391_399_902., epsilon = 1.);
+    }
+
+    #[test]
+    fn geodesic_area_multi_polygon_test() {
+        let poly0 = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        let mpoly = MultiPolygon(vec![poly0.clone(), poly0]);
+        assert_relative_eq!(mpoly.geodesic_area(), -24_782_799_804., epsilon = 1.);
+    }
+
+    #[test]
+    fn geodesic_area_antimeridian_test() {
+        // A ring straddling the antimeridian should not produce a wildly
+        // inflated area from an unwrapped longitude jump.
+        let polygon = polygon![
+            (x: 179., y: 0.),
+            (x: -179., y: 0.),
+            (x: -179., y: 1.),
+            (x: 179., y: 1.),
+            (x: 179., y: 0.),
+        ];
+        assert_relative_eq!(polygon.geodesic_area().abs(), 24_782_799_804., epsilon = 1.);
+    }
+
+    #[test]
+    fn geodesic_area_polygon_inner_test() {
+        let poly = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+                (x: 0., y: 0.),
+            ],
+            interiors: [
+                [
+                    (x: 1., y: 1.),
+                    (x: 2., y: 1.),
+                    (x: 2., y: 2.),
+                    (x: 1., y: 2.),
+                    (x: 1., y: 1.),
+                ],
+            ],
+        ];
+        assert_relative_eq!(poly.geodesic_area(), -1_220_533_473_209., epsilon = 1.);
+    }
+
+    #[test]
+    fn geodesic_area_rect_test() {
+        let rect: Rect<f64> =
+            Rect::new(Coordinate { x: 10., y: 30. }, Coordinate { x: 20., y: 40. });
+        assert_relative_eq!(rect.geodesic_area(), -1_013_807_682_651., epsilon = 1.);
+    }
+
+    #[test]
+    fn geodesic_area_triangle_test() {
+        let triangle = Triangle(
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: 1., y: 0. },
+            Coordinate { x: 0., y: 1. },
+        );
+        assert_relative_eq!(triangle.geodesic_area(), -6_195_699_951., epsilon = 1.);
+    }
+
+    #[test]
+    fn geodesic_area_geometry_collection_test() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        let triangle = Triangle(
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: 1., y: 0. },
+            Coordinate { x: 0., y: 1. },
+        );
+        let geometry_collection =
+            GeometryCollection(vec![Geometry::Polygon(polygon), Geometry::Triangle(triangle)]);
+        assert_relative_eq!(
+            geometry_collection.geodesic_area(),
+            -18_587_099_853.,
+            epsilon = 1.
+        );
+    }
 }
@@ -0,0 +1,187 @@
+use crate::{GeometryCollection, GeometryCow, LineString, MultiPolygon, Polygon, Rect, Triangle};
+use geo_types::private_utils::line_euclidean_length;
+use num_traits::Float;
+
+/// Euclidean length of a geometry's boundary.
+///
+/// For a `Polygon` this is its perimeter (the exterior ring plus all interior
+/// rings); for a `LineString` it's the summed length of its segments.
+/// `Point`/`MultiPoint` have no boundary and so return zero.
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo::algorithm::euclidean_length::EuclideanLength;
+///
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 5., y: 0.),
+///     (x: 5., y: 6.),
+///     (x: 0., y: 6.),
+///     (x: 0., y: 0.),
+/// ];
+///
+/// assert_eq!(polygon.euclidean_length(), 22.);
+/// ```
+pub trait EuclideanLength<'a, T>
+where
+    T: Float,
+{
+    fn euclidean_length(&'a self) -> T;
+}
+
+fn length_linestring<T: Float>(linestring: &LineString<T>) -> T {
+    linestring
+        .lines()
+        .fold(T::zero(), |total, line| total + line_euclidean_length(line))
+}
+
+fn length_polygon<T: Float>(polygon: &Polygon<T>) -> T {
+    polygon.interiors().iter().fold(
+        length_linestring(polygon.exterior()),
+        |total, next| total + length_linestring(next),
+    )
+}
+
+fn length_multi_polygon<T: Float>(multi_polygon: &MultiPolygon<T>) -> T {
+    multi_polygon
+        .0
+        .iter()
+        .fold(T::zero(), |total, next| total + length_polygon(next))
+}
+
+fn length_geometry_collection<T: Float>(geometry_collection: &GeometryCollection<T>) -> T {
+    geometry_collection
+        .iter()
+        .fold(T::zero(), |total, geometry| total + geometry.euclidean_length())
+}
+
+fn length_rect<T: Float>(rect: &Rect<T>) -> T {
+    (rect.width() + rect.height()) * (T::one() + T::one())
+}
+
+fn length_triangle<T: Float>(triangle: &Triangle<T>) -> T {
+    triangle
+        .to_lines()
+        .iter()
+        .fold(T::zero(), |total, line| total + line_euclidean_length(*line))
+}
+
+impl<'a, I: 'a, T: 'a> EuclideanLength<'a, T> for I
+where
+    &'a I: Into<GeometryCow<'a, T>>,
+    T: Float,
+{
+    fn euclidean_length(&'a self) -> T {
+        let geometry_cow: GeometryCow<'a, T> = self.into();
+        match geometry_cow {
+            GeometryCow::Point(_) => T::zero(),
+            GeometryCow::Line(g) => line_euclidean_length(*g),
+            GeometryCow::LineString(g) => length_linestring(&*g),
+            GeometryCow::Polygon(g) => length_polygon(&*g),
+            GeometryCow::MultiPoint(_) => T::zero(),
+            GeometryCow::MultiLineString(g) => g
+                .0
+                .iter()
+                .fold(T::zero(), |total, next| total + length_linestring(next)),
+            GeometryCow::MultiPolygon(g) => length_multi_polygon(&*g),
+            GeometryCow::GeometryCollection(g) => length_geometry_collection(&*g),
+            GeometryCow::Rect(g) => length_rect(&*g),
+            GeometryCow::Triangle(g) => length_triangle(&*g),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::algorithm::euclidean_length::EuclideanLength;
+    use crate::{line_string, polygon, Coordinate, Line, MultiPolygon, Polygon, Rect, Triangle};
+
+    #[test]
+    fn length_empty_linestring_test() {
+        let linestring: crate::LineString<f32> = line_string![];
+        assert_relative_eq!(linestring.euclidean_length(), 0.);
+    }
+
+    #[test]
+    fn length_linestring_test() {
+        let linestring = line_string![
+            (x: 0., y: 0.),
+            (x: 3., y: 0.),
+            (x: 3., y: 4.),
+        ];
+        assert_relative_eq!(linestring.euclidean_length(), 7.);
+    }
+
+    #[test]
+    fn length_polygon_test() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 5., y: 0.),
+            (x: 5., y: 6.),
+            (x: 0., y: 6.),
+            (x: 0., y: 0.),
+        ];
+        assert_relative_eq!(polygon.euclidean_length(), 22.);
+    }
+
+    #[test]
+    fn length_polygon_with_hole_test() {
+        let polygon = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+                (x: 0., y: 0.),
+            ],
+            interiors: [
+                [
+                    (x: 1., y: 1.),
+                    (x: 2., y: 1.),
+                    (x: 2., y: 2.),
+                    (x: 1., y: 2.),
+                    (x: 1., y: 1.),
+                ],
+            ],
+        ];
+        assert_relative_eq!(polygon.euclidean_length(), 40. + 4.);
+    }
+
+    #[test]
+    fn length_multi_polygon_test() {
+        let poly0 = polygon![
+            (x: 0., y: 0.),
+            (x: 5., y: 0.),
+            (x: 5., y: 6.),
+            (x: 0., y: 6.),
+            (x: 0., y: 0.),
+        ];
+        let mpoly = MultiPolygon(vec![poly0.clone(), poly0]);
+        assert_relative_eq!(mpoly.euclidean_length(), 44.);
+    }
+
+    #[test]
+    fn length_rect_test() {
+        let rect: Rect<f32> =
+            Rect::new(Coordinate { x: 10., y: 30. }, Coordinate { x: 20., y: 40. });
+        assert_relative_eq!(rect.euclidean_length(), 40.);
+    }
+
+    #[test]
+    fn length_triangle_test() {
+        let triangle = Triangle(
+            Coordinate { x: 0.0, y: 0.0 },
+            Coordinate { x: 3.0, y: 0.0 },
+            Coordinate { x: 3.0, y: 4.0 },
+        );
+        assert_relative_eq!(triangle.euclidean_length(), 12.);
+    }
+
+    #[test]
+    fn length_line_test() {
+        let line = Line::new(Coordinate { x: 0.0, y: 0.0 }, Coordinate { x: 3.0, y: 4.0 });
+        assert_relative_eq!(line.euclidean_length(), 5.);
+    }
+}
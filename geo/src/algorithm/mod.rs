@@ -0,0 +1,5 @@
+pub mod area;
+pub use self::area::{Area, GeodesicArea};
+
+pub mod euclidean_length;
+pub use self::euclidean_length::EuclideanLength;